@@ -2,8 +2,9 @@ extern crate ndarray;
 extern crate ndarray_parallel;
 extern crate num_traits;
 
-use ndarray::{Axis, Array1, Array3, ArrayViewMut1, Zip};
+use ndarray::{ArrayD, ArrayView1, Axis, Array1, Array2, Array3, ArrayViewMut1, IxDyn, Zip};
 use ndarray_parallel::prelude::*;
+use num_traits::Float;
 use std::error::Error;
 use std::fmt;
 
@@ -31,24 +32,38 @@ impl Error for InterpError {
     }
 }
 
-pub fn lerp(
-    x: &Array1<f32>,
-    y: &Array1<f32>,
-    xi: &Array1<f32>,
-) -> Result<Array1<f32>, InterpError> {
+/// Locates the interval `[x[i], x[i+1]]` that encloses `xi` in O(log n).
+///
+/// Relies on `x` being monotonically increasing (checked with a `debug_assert`
+/// in the callers, since re-checking it on every query would defeat the point
+/// of the binary search). The result is clamped to `[0, x.len() - 2]` so a
+/// query exactly on the last grid point still resolves to the final interval
+/// instead of walking off the end of `x`.
+fn find_interval<A: Float>(x: &[A], xi: A) -> usize {
+    let idx = x.partition_point(|&v| v <= xi);
+    idx.saturating_sub(1).min(x.len() - 2)
+}
+
+pub fn lerp<A: Float + Send + Sync>(
+    x: &Array1<A>,
+    y: &Array1<A>,
+    xi: &Array1<A>,
+) -> Result<Array1<A>, InterpError> {
+    debug_assert!(
+        x.windows(2).into_iter().all(|xw| xw[0] <= xw[1]),
+        "x must be monotonically increasing"
+    );
     // This check takes about 10% of the time.
     let xf = x.into_iter().next().ok_or(InterpError::NoneArray)?;
     let xl = x.into_iter().last().ok_or(InterpError::NoneArray)?;
     if xi.iter().any(|xi| xi < xf || xi > xl) {
         return Err(InterpError::Range);
     }
-    let mut output = Array1::<f32>::zeros(xi.len());
+    let xs = x.as_slice().ok_or(InterpError::NoneArray)?;
+    let mut output = Array1::<A>::zeros(xi.len());
     Zip::from(&mut output).and(xi).par_apply(|output, &xi| {
-        // We know xi is in range since we just checked, so unrwap is fine here
-        let xidx = x.windows(2)
-            .into_iter()
-            .position(|xw| xw[0] <= xi && xw[1] >= xi)
-            .unwrap();
+        // We know xi is in range since we just checked, so the interval is always found
+        let xidx = find_interval(xs, xi);
         let x0 = x[xidx];
         let x1 = x[xidx + 1];
         let y0 = y[xidx];
@@ -58,14 +73,22 @@ pub fn lerp(
     Ok(output)
 }
 
-pub fn lerp_unchecked(x: &Array1<f32>, y: &Array1<f32>, xi: &Array1<f32>) -> Array1<f32> {
-    let mut output = Array1::<f32>::zeros(xi.len());
+pub fn lerp_unchecked<A: Float + Send + Sync>(
+    x: &Array1<A>,
+    y: &Array1<A>,
+    xi: &Array1<A>,
+) -> Array1<A> {
+    debug_assert!(
+        x.windows(2).into_iter().all(|xw| xw[0] <= xw[1]),
+        "x must be monotonically increasing"
+    );
+    // Note we don't check that xi is in x: an out-of-range query silently
+    // extrapolates from the nearest edge interval instead of erroring, since
+    // find_interval clamps into [0, x.len() - 2]. So use with caution.
+    let xs = x.as_slice().expect("x must be contiguous and in standard order");
+    let mut output = Array1::<A>::zeros(xi.len());
     Zip::from(&mut output).and(xi).par_apply(|output, &xi| {
-        // Note we don't check that xi is in x and could possibly panic here. So use with caution.
-        let xidx = x.windows(2)
-            .into_iter()
-            .position(|xw| xw[0] <= xi && xw[1] >= xi)
-            .unwrap();
+        let xidx = find_interval(xs, xi);
         let x0 = x[xidx];
         let x1 = x[xidx + 1];
         let y0 = y[xidx];
@@ -75,21 +98,141 @@ pub fn lerp_unchecked(x: &Array1<f32>, y: &Array1<f32>, xi: &Array1<f32>) -> Arr
     output
 }
 
-pub fn trilerp_resize(_v: &Array3<f32>, size: usize) -> Array3<f32> {
-    // We're going to build a new array based on a new size.
-    // i.e, if we have v.size = 50x50x50, and size = 100
-    // then the output will be 100x100x100 linearly interpolated
-    // For now we'll assume square coords
-    let output = Array3::<f32>::zeros((size, size, size));
-    output
+/// Controls what [`lerp_with`] does with a query point outside `[x_first, x_last]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Boundary<A> {
+    /// Bail out with [`InterpError::Range`], as `lerp` does.
+    Error,
+    /// Pin the query to the nearest endpoint value.
+    Clamp,
+    /// Continue the slope of the first/last interval past the endpoint.
+    Extrapolate,
+    /// Substitute a fixed value (e.g. `A::nan()`).
+    Fill(A),
 }
 
-pub fn meshgrid(x: &mut Array1<f32>) -> (Array3<f32>, Array3<f32>, Array3<f32>) {
+pub fn lerp_with<A: Float + Send + Sync>(
+    x: &Array1<A>,
+    y: &Array1<A>,
+    xi: &Array1<A>,
+    boundary: Boundary<A>,
+) -> Result<Array1<A>, InterpError> {
+    debug_assert!(
+        x.windows(2).into_iter().all(|xw| xw[0] <= xw[1]),
+        "x must be monotonically increasing"
+    );
+    let xf = *x.into_iter().next().ok_or(InterpError::NoneArray)?;
+    let xl = *x.into_iter().last().ok_or(InterpError::NoneArray)?;
+    if boundary == Boundary::Error && xi.iter().any(|xi| xi < &xf || xi > &xl) {
+        return Err(InterpError::Range);
+    }
+    let xs = x.as_slice().ok_or(InterpError::NoneArray)?;
+    let mut output = Array1::<A>::zeros(xi.len());
+    Zip::from(&mut output).and(xi).par_apply(|output, &xi| {
+        // The common case is in range, so only the two comparisons below are
+        // paid by every element; the boundary handling itself only runs for
+        // out-of-range queries.
+        *output = if xi < xf {
+            match boundary {
+                Boundary::Error => unreachable!("checked out of range above"),
+                Boundary::Clamp => y[0],
+                Boundary::Fill(fill) => fill,
+                Boundary::Extrapolate => {
+                    let (x0, x1, y0, y1) = (x[0], x[1], y[0], y[1]);
+                    y0 + (xi - x0) * ((y1 - y0) / (x1 - x0))
+                }
+            }
+        } else if xi > xl {
+            match boundary {
+                Boundary::Error => unreachable!("checked out of range above"),
+                Boundary::Clamp => y[y.len() - 1],
+                Boundary::Fill(fill) => fill,
+                Boundary::Extrapolate => {
+                    let n = x.len();
+                    let (x0, x1, y0, y1) = (x[n - 2], x[n - 1], y[n - 2], y[n - 1]);
+                    y0 + (xi - x0) * ((y1 - y0) / (x1 - x0))
+                }
+            }
+        } else {
+            let xidx = find_interval(xs, xi);
+            let x0 = x[xidx];
+            let x1 = x[xidx + 1];
+            let y0 = y[xidx];
+            let y1 = y[xidx + 1];
+            y0 + (xi - x0) * ((y1 - y0) / (x1 - x0))
+        };
+    });
+    Ok(output)
+}
+
+/// Precomputes, for every index along an output axis of length `n_out`, the
+/// enclosing source cell and the fractional offset into it, by mapping the
+/// output voxel centre back onto the source axis `0..n_in` and reusing the
+/// same monotonic-index lookup as [`lerp`].
+fn trilerp_axis_map<A: Float>(n_in: usize, n_out: usize) -> Vec<(usize, A)> {
+    let src: Vec<A> = (0..n_in).map(|n| A::from(n).unwrap()).collect();
+    (0..n_out)
+        .map(|out_idx| {
+            let pos = if n_out <= 1 {
+                A::zero()
+            } else {
+                A::from(out_idx).unwrap() * A::from(n_in - 1).unwrap() / A::from(n_out - 1).unwrap()
+            };
+            let idx = find_interval(&src, pos);
+            (idx, pos - src[idx])
+        })
+        .collect()
+}
+
+pub fn trilerp_resize<A: Float + Send + Sync>(
+    v: &Array3<A>,
+    target: (usize, usize, usize),
+) -> Result<Array3<A>, InterpError> {
+    // We're going to build a new array based on a new shape.
+    // i.e, if we have v.size = 50x50x50, and target = (100, 100, 100)
+    // then the output will be 100x100x100 trilinearly interpolated.
+    let (ni, nj, nk) = v.dim();
+    if ni < 2 || nj < 2 || nk < 2 {
+        return Err(InterpError::NoneArray);
+    }
+    let (no, njo, nko) = target;
+    let i_map = trilerp_axis_map(ni, no);
+    let j_map = trilerp_axis_map(nj, njo);
+    let k_map = trilerp_axis_map(nk, nko);
+    let mut output = Array3::<A>::zeros(target);
+    let one = A::one();
+    Zip::indexed(&mut output).par_apply(|(oi, oj, ok), out| {
+        let (i0, fi) = i_map[oi];
+        let (j0, fj) = j_map[oj];
+        let (k0, fk) = k_map[ok];
+
+        let c000 = v[[i0, j0, k0]];
+        let c100 = v[[i0 + 1, j0, k0]];
+        let c010 = v[[i0, j0 + 1, k0]];
+        let c110 = v[[i0 + 1, j0 + 1, k0]];
+        let c001 = v[[i0, j0, k0 + 1]];
+        let c101 = v[[i0 + 1, j0, k0 + 1]];
+        let c011 = v[[i0, j0 + 1, k0 + 1]];
+        let c111 = v[[i0 + 1, j0 + 1, k0 + 1]];
+
+        *out = c000 * (one - fi) * (one - fj) * (one - fk)
+            + c100 * fi * (one - fj) * (one - fk)
+            + c010 * (one - fi) * fj * (one - fk)
+            + c110 * fi * fj * (one - fk)
+            + c001 * (one - fi) * (one - fj) * fk
+            + c101 * fi * (one - fj) * fk
+            + c011 * (one - fi) * fj * fk
+            + c111 * fi * fj * fk;
+    });
+    Ok(output)
+}
+
+pub fn meshgrid<A: Float + Send + Sync>(x: &mut Array1<A>) -> (Array3<A>, Array3<A>, Array3<A>) {
     let nx = x.len();
 
-    let mut xx = Array3::<f32>::zeros((nx,nx,nx));
-    let mut yy = Array3::<f32>::zeros((nx,nx,nx));
-    let mut zz = Array3::<f32>::zeros((nx,nx,nx));
+    let mut xx = Array3::<A>::zeros((nx,nx,nx));
+    let mut yy = Array3::<A>::zeros((nx,nx,nx));
+    let mut zz = Array3::<A>::zeros((nx,nx,nx));
 
     for mut lane in xx.lanes_mut(Axis(0)).into_iter() {
         //TODO: There should be a nicer way to assign this.
@@ -113,16 +256,189 @@ pub fn meshgrid(x: &mut Array1<f32>) -> (Array3<f32>, Array3<f32>, Array3<f32>)
     (xx, yy, zz)
 }
 
+/// A natural cubic spline through `(x, y)`, offering C² continuity where
+/// [`lerp`] only gives a piecewise-linear fit. Construction solves the
+/// tridiagonal system for the second derivatives once; [`CubicSpline::eval`]
+/// and [`CubicSpline::eval_array`] are then cheap per-point evaluations.
+pub struct CubicSpline<A> {
+    x: Array1<A>,
+    y: Array1<A>,
+    /// Second derivatives at each node (natural boundary: `m[0] = m[n-1] = 0`).
+    m: Array1<A>,
+}
+
+impl<A: Float + Send + Sync> CubicSpline<A> {
+    /// Builds the spline, solving the natural-spline tridiagonal system via
+    /// the Thomas algorithm in O(n).
+    pub fn new(x: &Array1<A>, y: &Array1<A>) -> Result<Self, InterpError> {
+        let n = x.len();
+        if n < 2 || y.len() != n {
+            return Err(InterpError::NoneArray);
+        }
+        debug_assert!(
+            x.windows(2).into_iter().all(|xw| xw[0] <= xw[1]),
+            "x must be monotonically increasing"
+        );
+
+        let h: Vec<A> = x.windows(2).into_iter().map(|xw| xw[1] - xw[0]).collect();
+
+        let mut a = vec![A::zero(); n];
+        let mut b = vec![A::zero(); n];
+        let mut c = vec![A::zero(); n];
+        let mut d = vec![A::zero(); n];
+
+        let three = A::from(3).unwrap();
+        let six = A::from(6).unwrap();
+        for i in 1..n - 1 {
+            a[i] = h[i - 1] / six;
+            b[i] = (h[i - 1] + h[i]) / three;
+            c[i] = h[i] / six;
+            d[i] = (y[i + 1] - y[i]) / h[i] - (y[i] - y[i - 1]) / h[i - 1];
+        }
+        // Natural boundary conditions: m[0] = m[n - 1] = 0.
+        b[0] = A::one();
+        b[n - 1] = A::one();
+
+        // Thomas algorithm: forward elimination, then back substitution.
+        let mut cp = vec![A::zero(); n];
+        let mut dp = vec![A::zero(); n];
+        cp[0] = c[0] / b[0];
+        dp[0] = d[0] / b[0];
+        for i in 1..n {
+            let denom = b[i] - a[i] * cp[i - 1];
+            cp[i] = c[i] / denom;
+            dp[i] = (d[i] - a[i] * dp[i - 1]) / denom;
+        }
+        let mut m = vec![A::zero(); n];
+        m[n - 1] = dp[n - 1];
+        for i in (0..n - 1).rev() {
+            m[i] = dp[i] - cp[i] * m[i + 1];
+        }
+
+        Ok(CubicSpline {
+            x: x.clone(),
+            y: y.clone(),
+            m: Array1::from_vec(m),
+        })
+    }
+
+    /// Evaluates the spline at a single point via the Hermite form of the
+    /// interval located by [`find_interval`].
+    pub fn eval(&self, xi: A) -> A {
+        let xs = self
+            .x
+            .as_slice()
+            .expect("x must be contiguous and in standard order");
+        let idx = find_interval(xs, xi);
+        let x0 = self.x[idx];
+        let x1 = self.x[idx + 1];
+        let y0 = self.y[idx];
+        let y1 = self.y[idx + 1];
+        let m0 = self.m[idx];
+        let m1 = self.m[idx + 1];
+        let h = x1 - x0;
+        let six = A::from(6).unwrap();
+
+        m0 * (x1 - xi).powi(3) / (six * h)
+            + m1 * (xi - x0).powi(3) / (six * h)
+            + (y0 / h - m0 * h / six) * (x1 - xi)
+            + (y1 / h - m1 * h / six) * (xi - x0)
+    }
+
+    /// Evaluates the spline at every point in `xi`, fanned out over `par_apply`
+    /// the same way [`lerp`] parallelizes its queries.
+    pub fn eval_array(&self, xi: &Array1<A>) -> Array1<A> {
+        let mut output = Array1::<A>::zeros(xi.len());
+        Zip::from(&mut output).and(xi).par_apply(|output, &xi| {
+            *output = self.eval(xi);
+        });
+        output
+    }
+}
+
+/// Multilinear interpolation over an N-dimensional regular grid, generalizing
+/// [`lerp`] (N=1) and [`trilerp_resize`]'s trilinear blend (N=3) to arbitrary
+/// rank so 2D image resampling and ≥4D field data don't need axis-specific code.
+pub struct RegularGridInterpolator<A> {
+    /// Strictly increasing coordinate vector for each axis.
+    axes: Vec<Array1<A>>,
+    /// Sample values, with shape matching `axes.iter().map(Array1::len)`.
+    values: ArrayD<A>,
+}
+
+impl<A: Float + Send + Sync> RegularGridInterpolator<A> {
+    pub fn new(axes: Vec<Array1<A>>, values: ArrayD<A>) -> Result<Self, InterpError> {
+        if axes.len() != values.ndim() {
+            return Err(InterpError::NoneArray);
+        }
+        for (axis, &len) in axes.iter().zip(values.shape()) {
+            if axis.len() != len || axis.len() < 2 {
+                return Err(InterpError::NoneArray);
+            }
+            debug_assert!(
+                axis.windows(2).into_iter().all(|xw| xw[0] < xw[1]),
+                "axes must be strictly increasing"
+            );
+        }
+        Ok(RegularGridInterpolator { axes, values })
+    }
+
+    /// Interpolates at every row of `points`, where each row is an
+    /// N-dimensional query with one coordinate per axis.
+    pub fn interp(&self, points: &Array2<A>) -> Result<Array1<A>, InterpError> {
+        if points.cols() != self.axes.len() {
+            return Err(InterpError::NoneArray);
+        }
+        let mut output = Array1::<A>::zeros(points.rows());
+        Zip::indexed(&mut output).par_apply(|i, out| {
+            *out = self.interp_point(points.row(i));
+        });
+        Ok(output)
+    }
+
+    /// Binary-searches each axis for the lower corner index and fractional
+    /// offset, then blends the `2^N` corners of the enclosing hypercube,
+    /// weighting each corner by the product over axes of (offset or 1-offset).
+    fn interp_point(&self, point: ArrayView1<A>) -> A {
+        let ndim = self.axes.len();
+        let mut lower = vec![0usize; ndim];
+        let mut frac = vec![A::zero(); ndim];
+        for d in 0..ndim {
+            let axis = self.axes[d]
+                .as_slice()
+                .expect("axis must be contiguous and in standard order");
+            let xi = point[d];
+            let idx = find_interval(axis, xi);
+            lower[d] = idx;
+            let x0 = axis[idx];
+            let x1 = axis[idx + 1];
+            frac[d] = (xi - x0) / (x1 - x0);
+        }
+
+        let mut acc = A::zero();
+        let mut corner_idx = vec![0usize; ndim];
+        for corner in 0..(1usize << ndim) {
+            let mut weight = A::one();
+            for d in 0..ndim {
+                let bit = (corner >> d) & 1;
+                corner_idx[d] = lower[d] + bit;
+                weight = weight * if bit == 1 { frac[d] } else { A::one() - frac[d] };
+            }
+            acc = acc + weight * self.values[IxDyn(&corner_idx)];
+        }
+        acc
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use ndarray::{Array, Array3};
     use num_traits::float::Float;
-    use std::f32::consts::PI;
 
     #[test]
     fn interp_l() {
-        let x = Array::linspace(1., 10., 10);
+        let x: Array1<f32> = Array::linspace(1., 10., 10);
         let y = Array::from_iter(x.into_iter().map(|x| x.sin()));
         let xi = Array::linspace(1., 10., 20);
 
@@ -156,7 +472,7 @@ mod tests {
 
     #[test]
     fn interp_l_unckecked() {
-        let x = Array::linspace(1., 10., 10);
+        let x: Array1<f32> = Array::linspace(1., 10., 10);
         let y = Array::from_iter(x.into_iter().map(|x| x.sin()));
         let xi = Array::linspace(1., 10., 20);
 
@@ -189,16 +505,120 @@ mod tests {
     }
 
     #[test]
-    fn trilinear_resize() {
-        let n = 3.;
-        let mut xn = Array::linspace(-n, n, 5);
-        let (x,y,z) = meshgrid(&mut xn);
+    fn lerp_with_clamp() {
+        let x = Array::from_vec(vec![0., 1., 2.]);
+        let y = Array::from_vec(vec![0., 10., 20.]);
+        let xi = Array::from_vec(vec![-1., 0.5, 3.]);
+
+        let yi = lerp_with(&x, &y, &xi, Boundary::Clamp).unwrap();
+        assert_eq!(yi, Array::from_vec(vec![0., 5., 20.]));
+    }
 
-        let mut v = Array3::<f32>::zeros((5,5,5));
-        Zip::from(&mut v).and(&x).and(&y).and(&z).apply(|v, &x, &y, &z| {
-            *v = 1000./(2.*PI).sqrt()*(-(x.powi(2)/2.)-(y.powi(2)/2.)-(z.powi(2)/2.)).exp();
+    #[test]
+    fn lerp_with_extrapolate() {
+        let x = Array::from_vec(vec![0., 1., 2.]);
+        let y = Array::from_vec(vec![0., 10., 20.]);
+        let xi = Array::from_vec(vec![-1., 3.]);
+
+        let yi = lerp_with(&x, &y, &xi, Boundary::Extrapolate).unwrap();
+        assert_eq!(yi, Array::from_vec(vec![-10., 30.]));
+    }
+
+    #[test]
+    fn lerp_with_fill() {
+        let x = Array::from_vec(vec![0., 1., 2.]);
+        let y = Array::from_vec(vec![0., 10., 20.]);
+        let xi = Array::from_vec(vec![-1., 0.5]);
+
+        let yi = lerp_with(&x, &y, &xi, Boundary::Fill(f32::NAN)).unwrap();
+        assert!(yi[0].is_nan());
+        assert_eq!(yi[1], 5.);
+    }
+
+    #[test]
+    fn lerp_with_error() {
+        let x = Array::from_vec(vec![0., 1., 2.]);
+        let y = Array::from_vec(vec![0., 10., 20.]);
+        let xi = Array::from_vec(vec![-1.]);
+
+        assert!(lerp_with(&x, &y, &xi, Boundary::Error).is_err());
+    }
+
+    #[test]
+    fn trilinear_resize() {
+        // A field that is linear along each axis is reproduced exactly by
+        // trilinear interpolation, which pins down the corner-weighting math.
+        let mut v = Array3::<f32>::zeros((2, 2, 2));
+        Zip::indexed(&mut v).apply(|(i, j, k), val| {
+            *val = i as f32 + j as f32 + k as f32;
         });
-        println!("{}", v);
-        assert!(false);
+
+        let resized = trilerp_resize(&v, (3, 3, 3)).unwrap();
+        for (i, expected) in [0., 0.5, 1.].iter().enumerate() {
+            assert!((resized[[i, 0, 0]] - expected).abs() < 1e-6);
+            assert!((resized[[0, i, 0]] - expected).abs() < 1e-6);
+            assert!((resized[[0, 0, i]] - expected).abs() < 1e-6);
+        }
+        assert!((resized[[2, 2, 2]] - 3.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn trilinear_resize_rejects_degenerate_input() {
+        let v = Array3::<f32>::zeros((1, 4, 4));
+        assert!(trilerp_resize(&v, (2, 2, 2)).is_err());
+    }
+
+    #[test]
+    fn cubic_spline_reproduces_nodes() {
+        let x = Array::from_vec(vec![0., 1., 2., 3.]);
+        let y = Array::from_vec(vec![0., 1., 0., 1.]);
+
+        let spline = CubicSpline::new(&x, &y).unwrap();
+        for (&xi, &yi) in x.iter().zip(y.iter()) {
+            assert!((spline.eval(xi) - yi).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn cubic_spline_eval_array_matches_linear_data() {
+        let x = Array::from_vec(vec![0., 1., 2., 3., 4.]);
+        let y = Array::from_vec(vec![0., 2., 4., 6., 8.]);
+
+        let spline = CubicSpline::new(&x, &y).unwrap();
+        let xi = Array::from_vec(vec![0.5, 1.5, 2.5, 3.5]);
+        let yi = spline.eval_array(&xi);
+        assert_eq!(yi, Array::from_vec(vec![1., 3., 5., 7.]));
+    }
+
+    #[test]
+    fn regular_grid_subsumes_lerp_1d() {
+        let axis = Array1::from_vec(vec![0., 1., 2., 3.]);
+        let values = Array::from_vec(vec![0., 10., 20., 30.]).into_dyn();
+
+        let interp = RegularGridInterpolator::new(vec![axis], values).unwrap();
+        let points = Array2::from_shape_vec((2, 1), vec![0.5, 2.5]).unwrap();
+        let yi = interp.interp(&points).unwrap();
+        assert_eq!(yi, Array::from_vec(vec![5., 25.]));
+    }
+
+    #[test]
+    fn regular_grid_bilinear_2d() {
+        let x = Array1::from_vec(vec![0., 1.]);
+        let y = Array1::from_vec(vec![0., 1.]);
+        let values = Array::from_shape_vec((2, 2), vec![0., 1., 2., 3.])
+            .unwrap()
+            .into_dyn();
+
+        let interp = RegularGridInterpolator::new(vec![x, y], values).unwrap();
+        let points = Array2::from_shape_vec((1, 2), vec![0.5, 0.5]).unwrap();
+        let yi = interp.interp(&points).unwrap();
+        assert!((yi[0] - 1.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn regular_grid_rejects_axis_shape_mismatch() {
+        let axis = Array1::from_vec(vec![0., 1., 2.]);
+        let values = Array::from_vec(vec![0., 1.]).into_dyn();
+        assert!(RegularGridInterpolator::new(vec![axis], values).is_err());
     }
 }